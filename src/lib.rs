@@ -50,6 +50,7 @@
 //!     assert!(!leds.is_set(14));
 //! }
 //! ```
+use std::cmp;
 use std::fmt;
 
 /// A contiguous grow-able array type consisting of a list of bits.
@@ -73,8 +74,52 @@ use std::fmt;
 pub struct BitArrayList {
     bytes: Vec<u8>,
     length: usize,
+    // Bit offset into `bytes[0]` marking the start of the live region. Bits before `head` in the
+    // first byte have already been removed by `pop_front` but are left in place until a whole
+    // byte's worth has accumulated, at which point that leading byte is dropped and `head -= 8`.
+    head: usize,
 }
 
+/// Error returned by [try_from_vec()][13] when a byte slice cannot be parsed back into a
+/// `BitArrayList`.
+///
+/// [13]: struct.BitArrayList.html#method.try_from_vec
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is shorter than the 8-byte little-endian length header.
+    Truncated,
+    /// The payload does not contain exactly `ceil(length / 8)` bytes as implied by the header.
+    LengthMismatch {
+        /// Payload length implied by the header.
+        expected: usize,
+        /// Payload length actually found.
+        found: usize,
+    },
+    /// Bits at positions `>= length` in the final byte are non-zero.
+    TrailingBitsNotZero,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::Truncated => {
+                write!(f, "input is shorter than the 8-byte length header")
+            }
+            ParseError::LengthMismatch { expected, found } => {
+                write!(f,
+                       "payload has {} bytes but the header implies {}",
+                       found,
+                       expected)
+            }
+            ParseError::TrailingBitsNotZero => {
+                write!(f, "bits beyond the declared length in the final byte are not zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 impl BitArrayList {
     /// Returns the bit at a given index `i`.
     ///
@@ -99,7 +144,7 @@ impl BitArrayList {
     /// ```
     pub fn is_set(&self, bit_index: usize) -> bool {
         if bit_index < self.length {
-            let (byte_index, bit_position) = split_index(bit_index);
+            let (byte_index, bit_position) = split_index(self.head + bit_index);
 
             self.zero_testing(byte_index, bit_position)
         } else {
@@ -109,6 +154,188 @@ impl BitArrayList {
         }
     }
 
+    /// Returns an iterator yielding every bit in the collection, front-to-back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![160], 4);
+    ///
+    /// let bits: Vec<bool> = bit_array.iter().collect();
+    /// assert_eq!(bits, vec![true, false, true, false]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            bit_array: self,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator yielding the indices of only the bits that are set to `1`.
+    ///
+    /// Whole zero bytes are skipped eight positions at a time, so sparse arrays are cheap to
+    /// iterate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0, 160], 12);
+    ///
+    /// let indices: Vec<usize> = bit_array.ones().collect();
+    /// assert_eq!(indices, vec![8, 10]);
+    /// ```
+    pub fn ones(&self) -> Ones<'_> {
+        Ones {
+            bit_array: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the number of bits set to `1`.
+    ///
+    /// Runs in `O(bytes)`: whole bytes are counted with `u8::count_ones` and only the first and
+    /// last relevant bytes are masked down to the live range first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b11010110, 0b10000000], 9);
+    ///
+    /// assert_eq!(bit_array.count_ones(), 6);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.count_ones_in_range(self.head, self.head + self.length)
+    }
+
+    /// Returns the number of bits set to `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b11010110, 0b10000000], 9);
+    ///
+    /// assert_eq!(bit_array.count_zeros(), 3);
+    /// ```
+    pub fn count_zeros(&self) -> usize {
+        self.length - self.count_ones()
+    }
+
+    /// Returns the number of bits set to `1` at an index strictly before `i`.
+    ///
+    /// # Panics
+    ///
+    /// If `i > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b11010110], 8);
+    ///
+    /// assert_eq!(bit_array.rank(4), 3);
+    /// assert_eq!(bit_array.rank(0), 0);
+    /// ```
+    pub fn rank(&self, i: usize) -> usize {
+        if i > self.length {
+            panic!("BitArrayList index out of bounds: index is {} but array length is {}.",
+                   i,
+                   self.length);
+        }
+        self.count_ones_in_range(self.head, self.head + i)
+    }
+
+    /// Returns the index of the `k`-th (`0`-based) bit set to `1`, or `None` if there are fewer
+    /// than `k + 1` set bits.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b11010110], 8);
+    ///
+    /// assert_eq!(bit_array.select(0), Some(0));
+    /// assert_eq!(bit_array.select(2), Some(3));
+    /// assert_eq!(bit_array.select(4), Some(6));
+    /// assert_eq!(bit_array.select(5), None);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<usize> {
+        if self.length == 0 {
+            return None;
+        }
+        let (first_byte, first_bit) = split_index(self.head);
+        let (last_byte, last_bit) = split_index(self.head + self.length - 1);
+        let mut remaining = k;
+
+        for byte_index in first_byte..=last_byte {
+            let mut byte = self.bytes[byte_index];
+
+            if byte_index == first_byte {
+                byte &= 0xFFu8 >> first_bit;
+            }
+            if byte_index == last_byte {
+                byte &= 0xFFu8 << (7 - last_bit);
+            }
+            let ones_in_byte = byte.count_ones() as usize;
+
+            if remaining >= ones_in_byte {
+                remaining -= ones_in_byte;
+                continue;
+            }
+            for bit_position in 0..8u8 {
+                if byte & bitmask(bit_position) != 0 {
+                    if remaining == 0 {
+                        return Some(byte_index * 8 + bit_position as usize - self.head);
+                    }
+                    remaining -= 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Counts the bits set to `1` in the physical bit range `physical_start .. physical_end`
+    /// (indices into `bytes`, already offset by `head`), masking the first and last relevant bytes down to that
+    /// range and using `u8::count_ones` on the whole bytes in between.
+    fn count_ones_in_range(&self, physical_start: usize, physical_end: usize) -> usize {
+        if physical_start >= physical_end {
+            return 0;
+        }
+        let (first_byte, first_bit) = split_index(physical_start);
+        let (last_byte, last_bit) = split_index(physical_end - 1);
+
+        if first_byte == last_byte {
+            let mask = (0xFFu8 >> first_bit) & (0xFFu8 << (7 - last_bit));
+
+            return (self.bytes[first_byte] & mask).count_ones() as usize;
+        }
+
+        let mut count = (self.bytes[first_byte] & (0xFFu8 >> first_bit)).count_ones() as usize;
+
+        for byte in &self.bytes[first_byte + 1..last_byte] {
+            count += byte.count_ones() as usize;
+        }
+        count += (self.bytes[last_byte] & (0xFFu8 << (7 - last_bit))).count_ones() as usize;
+
+        count
+    }
+
     /// Returns the number of elements in the bit array.
     ///
     /// # Examples
@@ -146,7 +373,14 @@ impl BitArrayList {
     /// Returns the raw underlying array data structure of bytes.
     ///
     /// Note that this array may contain wasted space and without knowing the length of the bit
-    /// array at the time we cannot successfully recreate the bit array from this operation.
+    /// array at the time we cannot successfully recreate the bit array from this operation: the
+    /// first byte may carry up to 7 already-consumed bits before the array's internal head offset
+    /// (left behind by [pop_front()][10] until a whole byte's worth accumulates and is dropped),
+    /// and the last byte may carry up to 7 not-yet-used bits past the end of the array. Callers
+    /// that need a clean, self-describing encoding should use [to_vec()][15] instead.
+    ///
+    /// [10]: struct.BitArrayList.html#method.pop_front
+    /// [15]: struct.BitArrayList.html#method.to_vec
     ///
     /// # Examples
     ///
@@ -212,7 +446,7 @@ impl BitArrayList {
     /// ```
     pub fn set_bit_to(&mut self, bit_index: usize, bit: u8) {
         if bit_index < self.length {
-            let (byte_index, bit_position) = split_index(bit_index);
+            let (byte_index, bit_position) = split_index(self.head + bit_index);
             // Set bit to user's preferences.
             match bit {
                 1 => self.bytes[byte_index] |= bitmask(bit_position),
@@ -226,6 +460,84 @@ impl BitArrayList {
         }
     }
 
+    /// Writes the low `width` bits of `value` into the bit range `start .. start + width`,
+    /// most-significant-bit first: bit `start` receives `(value >> (width - 1)) & 1` and bit
+    /// `start + width - 1` receives `value & 1`.
+    ///
+    /// # Panics
+    ///
+    /// If `start + width > self.len()` or `width > 64`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let mut bit_array = BitArrayList::from(vec![0], 8);
+    ///
+    /// bit_array.store_uint(2, 4, 0b1011);
+    /// assert_eq!(bit_array.bytes(), &vec![0b00101100]);
+    /// ```
+    pub fn store_uint(&mut self, start: usize, width: usize, value: u64) {
+        if width > 64 {
+            panic!("Mismatched width: expected a width no greater than 64 but got {}.",
+                   width);
+        }
+        if start + width > self.length {
+            panic!("BitArrayList index out of bounds: range is {}..{} but array length is {}.",
+                   start,
+                   start + width,
+                   self.length);
+        }
+        for offset in 0..width {
+            let bit = (value >> (width - 1 - offset)) & 1;
+
+            self.set_bit_to(start + offset, bit as u8);
+        }
+    }
+
+    /// Reads the bit range `start .. start + width` as an unsigned integer, most-significant-bit
+    /// first: the inverse of [store_uint()][7].
+    ///
+    /// [7]: struct.BitArrayList.html#method.store_uint
+    ///
+    /// # Panics
+    ///
+    /// If `start + width > self.len()` or `width > 64`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b00101100], 8);
+    ///
+    /// assert_eq!(bit_array.load_uint(2, 4), 0b1011);
+    /// ```
+    pub fn load_uint(&self, start: usize, width: usize) -> u64 {
+        if width > 64 {
+            panic!("Mismatched width: expected a width no greater than 64 but got {}.",
+                   width);
+        }
+        if start + width > self.length {
+            panic!("BitArrayList index out of bounds: range is {}..{} but array length is {}.",
+                   start,
+                   start + width,
+                   self.length);
+        }
+        let mut acc: u64 = 0;
+
+        for offset in 0..width {
+            let bit = self.is_set(start + offset) as u64;
+
+            acc = (acc << 1) | bit;
+        }
+
+        acc
+    }
+
     /// Appends a given bit to the back of the collection of bits.
     ///
     /// # Panics
@@ -246,7 +558,7 @@ impl BitArrayList {
     /// assert_eq!(bit_array.bytes(), &vec![200, 129, 128]);
     /// ```
     pub fn push(&mut self, bit: u8) {
-        let (byte_index, bit_position) = split_index(self.length);
+        let (byte_index, bit_position) = split_index(self.head + self.length);
 
         if byte_index > self.bytes.len() - 1 {
             // Add new empty byte.
@@ -284,7 +596,7 @@ impl BitArrayList {
         }
         // Initialise  indexes.
         let last_bit_index = self.length - 1;
-        let (byte_index, bit_position) = split_index(last_bit_index);
+        let (byte_index, bit_position) = split_index(self.head + last_bit_index);
 
         let to_return = Some(self.zero_testing(byte_index, bit_position));
 
@@ -301,6 +613,87 @@ impl BitArrayList {
         to_return
     }
 
+    /// Removes and returns the first bit in the collection (true FIFO dequeue) unless the
+    /// collection is empty where `None` is returned.
+    ///
+    /// Where [pop()][11] removes from the back, this removes from the front. It does not shift
+    /// the remaining bytes on every call: an internal
+    /// `head` offset is advanced instead, and the leading byte is only dropped from storage once a
+    /// whole byte's worth of bits has been consumed.
+    ///
+    /// [11]: struct.BitArrayList.html#method.pop
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let mut bit_array = BitArrayList::from(vec![128], 2);
+    ///
+    /// assert_eq!(bit_array.pop_front(), Some(true));
+    /// assert_eq!(bit_array.pop_front(), Some(false));
+    /// assert_eq!(bit_array.pop_front(), None);
+    /// ```
+    ///
+    /// Dropping a whole leading byte still leaves the array usable:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let mut bit_array = BitArrayList::from(vec![0b11111111], 8);
+    ///
+    /// for _ in 0..8 {
+    ///     bit_array.pop_front();
+    /// }
+    /// assert!(bit_array.is_empty());
+    ///
+    /// bit_array.push(1);
+    /// assert_eq!(bit_array.bytes(), &vec![128]);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<bool> {
+        if self.is_empty() {
+            return None;
+        }
+        let to_return = Some(self.is_set(0));
+
+        self.head += 1;
+        self.length -= 1;
+
+        if self.head >= 8 {
+            // The leading byte no longer holds any live bits.
+            self.bytes.remove(0);
+            self.head -= 8;
+
+            if self.bytes.is_empty() {
+                // Keep the one-byte floor every other method relies on.
+                self.bytes.push(0);
+            }
+        }
+
+        to_return
+    }
+
+    /// Returns the first bit in the collection without removing it, or `None` if the collection
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![128], 2);
+    ///
+    /// assert_eq!(bit_array.front(), Some(true));
+    /// ```
+    pub fn front(&self) -> Option<bool> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.is_set(0))
+        }
+    }
+
     /// Appends two bit arrays together (self followed by other bits).
     ///
     /// Leaves other bits array unusable (dropped by Rust).
@@ -327,7 +720,7 @@ impl BitArrayList {
     /// assert_eq!(bit_array.len(), 9 + 4);
     /// ```
     pub fn concatenate(&mut self, other_bits: BitArrayList) {
-        if self.length % 8 != 0 {
+        if !(self.head + self.length).is_multiple_of(8) || other_bits.head != 0 {
             // Push each bit in self from BitArrayList we are extending self with.
             for bit_index in 0..other_bits.length {
                 match other_bits.is_set(bit_index) {
@@ -339,6 +732,7 @@ impl BitArrayList {
             // No need to fill empty space at last byte.
             if self.is_empty() {
                 self.bytes = other_bits.bytes;
+                self.head = 0;
             } else {
                 self.bytes.extend(other_bits.bytes.into_iter());
             }
@@ -365,6 +759,7 @@ impl BitArrayList {
         BitArrayList {
             bytes: vec![0],
             length: 0,
+            head: 0,
         }
     }
 
@@ -393,7 +788,271 @@ impl BitArrayList {
         BitArrayList {
             bytes: b,
             length: l,
+            head: 0,
+        }
+    }
+
+    /// Serializes the bit array to a self-describing byte vector: an 8-byte little-endian length
+    /// header followed by the trimmed `ceil(length / 8)`-byte payload.
+    ///
+    /// Unlike [bytes()][14], the result carries its own length and so can be round-tripped back
+    /// into a `BitArrayList` with [try_from_vec()][13] without the caller tracking the length
+    /// separately.
+    ///
+    /// [13]: struct.BitArrayList.html#method.try_from_vec
+    /// [14]: struct.BitArrayList.html#method.bytes
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b10110000], 4);
+    /// let encoded = bit_array.to_vec();
+    ///
+    /// assert_eq!(encoded, vec![4, 0, 0, 0, 0, 0, 0, 0, 0b10110000]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<u8> {
+        let byte_len = self.length.div_ceil(8);
+        let mut payload = vec![0u8; byte_len];
+
+        for bit_index in 0..self.length {
+            if self.is_set(bit_index) {
+                let (byte_index, bit_position) = split_index(bit_index);
+
+                payload[byte_index] |= bitmask(bit_position);
+            }
+        }
+
+        let mut encoded = Vec::with_capacity(8 + payload.len());
+        encoded.extend_from_slice(&(self.length as u64).to_le_bytes());
+        encoded.extend(payload);
+
+        encoded
+    }
+
+    /// Parses a byte slice produced by [to_vec()][15] back into a `BitArrayList`.
+    ///
+    /// [15]: struct.BitArrayList.html#method.to_vec
+    ///
+    /// # Errors
+    ///
+    /// * [ParseError::Truncated][16] if `data` is shorter than the 8-byte length header.
+    /// * [ParseError::LengthMismatch][17] if the payload is not exactly `ceil(length / 8)` bytes.
+    /// * [ParseError::TrailingBitsNotZero][18] if bits at positions `>= length` in the final byte
+    ///   are non-zero.
+    ///
+    /// [16]: enum.ParseError.html#variant.Truncated
+    /// [17]: enum.ParseError.html#variant.LengthMismatch
+    /// [18]: enum.ParseError.html#variant.TrailingBitsNotZero
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let bit_array = BitArrayList::from(vec![0b10110000], 4);
+    /// let encoded = bit_array.to_vec();
+    ///
+    /// let decoded = BitArrayList::try_from_vec(&encoded).unwrap();
+    /// assert_eq!(decoded.len(), bit_array.len());
+    /// assert_eq!(decoded.bytes(), bit_array.bytes());
+    /// ```
+    pub fn try_from_vec(data: &[u8]) -> Result<BitArrayList, ParseError> {
+        if data.len() < 8 {
+            return Err(ParseError::Truncated);
+        }
+
+        let mut length_bytes = [0u8; 8];
+        length_bytes.copy_from_slice(&data[0..8]);
+        let length = u64::from_le_bytes(length_bytes) as usize;
+
+        let payload = &data[8..];
+        let expected_len = length.div_ceil(8);
+
+        if payload.len() != expected_len {
+            return Err(ParseError::LengthMismatch {
+                expected: expected_len,
+                found: payload.len(),
+            });
+        }
+
+        if !length.is_multiple_of(8) {
+            let (_, bit_position) = split_index(length - 1);
+            let keep_mask = 0xFFu8 << (7 - bit_position);
+            let last_byte = payload[payload.len() - 1];
+
+            if last_byte & !keep_mask != 0 {
+                return Err(ParseError::TrailingBitsNotZero);
+            }
+        }
+
+        let bytes = if payload.is_empty() { vec![0] } else { payload.to_vec() };
+
+        Ok(BitArrayList {
+            bytes,
+            length,
+            head: 0,
+        })
+    }
+
+    /// Returns a new `BitArrayList` that is the bitwise AND of `self` and `other`.
+    ///
+    /// The result's length is `max(self.len(), other.len())`---the shorter array's missing bits
+    /// are treated as `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let a = BitArrayList::from(vec![204], 8);
+    /// let b = BitArrayList::from(vec![240], 8);
+    ///
+    /// assert_eq!(a.and(&b).bytes(), &vec![192]);
+    /// ```
+    pub fn and(&self, other: &BitArrayList) -> BitArrayList {
+        self.bitwise_combine(other, |a, b| a & b)
+    }
+
+    /// Returns a new `BitArrayList` that is the bitwise OR of `self` and `other`.
+    ///
+    /// The result's length is `max(self.len(), other.len())`---the shorter array's missing bits
+    /// are treated as `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let a = BitArrayList::from(vec![204], 8);
+    /// let b = BitArrayList::from(vec![240], 8);
+    ///
+    /// assert_eq!(a.or(&b).bytes(), &vec![252]);
+    /// ```
+    pub fn or(&self, other: &BitArrayList) -> BitArrayList {
+        self.bitwise_combine(other, |a, b| a | b)
+    }
+
+    /// Returns a new `BitArrayList` that is the bitwise XOR of `self` and `other`.
+    ///
+    /// The result's length is `max(self.len(), other.len())`---the shorter array's missing bits
+    /// are treated as `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let a = BitArrayList::from(vec![204], 8);
+    /// let b = BitArrayList::from(vec![240], 8);
+    ///
+    /// assert_eq!(a.xor(&b).bytes(), &vec![60]);
+    /// ```
+    pub fn xor(&self, other: &BitArrayList) -> BitArrayList {
+        self.bitwise_combine(other, |a, b| a ^ b)
+    }
+
+    /// Flips every bit in the collection in place (bitwise NOT).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bit_array_list::BitArrayList;
+    /// let mut bit_array = BitArrayList::from(vec![204], 5);
+    ///
+    /// bit_array.negate();
+    /// assert_eq!(bit_array.bytes(), &vec![48]);
+    /// ```
+    pub fn negate(&mut self) {
+        for byte in &mut self.bytes {
+            *byte = !*byte;
+        }
+        self.normalize();
+    }
+
+    /// Combines `self` and `other` byte-wise using `op`, producing a new `BitArrayList` whose
+    /// length is `max(self.len(), other.len())`.
+    ///
+    /// Falls back to combining bit by bit if either side has a non-zero `head` (i.e. bits have
+    /// been removed from its front with [pop_front()][12]), since its bytes are then no longer
+    /// aligned with bit `0`.
+    ///
+    /// [12]: struct.BitArrayList.html#method.pop_front
+    fn bitwise_combine<F>(&self, other: &BitArrayList, op: F) -> BitArrayList
+        where F: Fn(u8, u8) -> u8
+    {
+        let result_length = cmp::max(self.length, other.length);
+
+        if self.head == 0 && other.head == 0 {
+            let byte_len = result_length.div_ceil(8);
+            let mut bytes = Vec::with_capacity(byte_len);
+            // Bits at or past a side's own length count as `0`, whatever its bytes still hold.
+            let live_byte = |bits: &BitArrayList, byte_index: usize| -> u8 {
+                let start = byte_index * 8;
+                let byte = *bits.bytes.get(byte_index).unwrap_or(&0);
+
+                if start >= bits.length {
+                    0
+                } else if bits.length - start < 8 {
+                    byte & (0xFFu8 << (8 - (bits.length - start)))
+                } else {
+                    byte
+                }
+            };
+
+            for byte_index in 0..byte_len {
+                let self_byte = live_byte(self, byte_index);
+                let other_byte = live_byte(other, byte_index);
+
+                bytes.push(op(self_byte, other_byte));
+            }
+
+            let mut result = BitArrayList {
+                bytes,
+                length: result_length,
+                head: 0,
+            };
+            result.normalize();
+
+            result
+        } else {
+            let mut result = BitArrayList::new();
+
+            for bit_index in 0..result_length {
+                let self_bit = bit_index < self.length && self.is_set(bit_index);
+                let other_bit = bit_index < other.length && other.is_set(bit_index);
+
+                result.push(op(self_bit as u8, other_bit as u8));
+            }
+
+            result
+        }
+    }
+
+    /// Clears all bits at positions `>= self.length` in the final byte so that operations like
+    /// [negate()][5] and [xor()][6] cannot leave garbage behind in the wasted space.
+    ///
+    /// [5]: struct.BitArrayList.html#method.negate
+    /// [6]: struct.BitArrayList.html#method.xor
+    fn normalize(&mut self) {
+        if self.length == 0 {
+            if let Some(last_byte) = self.bytes.last_mut() {
+                *last_byte = 0;
+            }
+            return;
         }
+        let (byte_index, bit_position) = split_index(self.head + self.length - 1);
+        let keep_mask = 0xFFu8 << (7 - bit_position);
+
+        self.bytes[byte_index] &= keep_mask;
     }
 
     /// Use this method to determine if bit at index `i` is set or not.
@@ -406,6 +1065,71 @@ impl BitArrayList {
     }
 }
 
+/// An iterator over the bits of a `BitArrayList`, created by [iter()][8].
+///
+/// [8]: struct.BitArrayList.html#method.iter
+pub struct Iter<'a> {
+    bit_array: &'a BitArrayList,
+    index: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.index < self.bit_array.length {
+            let bit = self.bit_array.is_set(self.index);
+            self.index += 1;
+
+            Some(bit)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BitArrayList {
+    type Item = bool;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the indices of the set bits of a `BitArrayList`, created by [ones()][9].
+///
+/// [9]: struct.BitArrayList.html#method.ones
+pub struct Ones<'a> {
+    bit_array: &'a BitArrayList,
+    index: usize,
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.index < self.bit_array.length {
+            let (byte_index, bit_position) = split_index(self.bit_array.head + self.index);
+
+            if bit_position == 0 && self.bit_array.bytes[byte_index] == 0 {
+                // Whole byte is zero, skip straight past it.
+                self.index += 8;
+                continue;
+            }
+            if self.bit_array.zero_testing(byte_index, bit_position) {
+                let found = self.index;
+                self.index += 1;
+
+                return Some(found);
+            }
+            self.index += 1;
+        }
+
+        None
+    }
+}
+
 impl fmt::Display for BitArrayList {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.length {
@@ -461,4 +1185,12 @@ mod tests {
         assert_eq!(super::split_index(8), (1, 0));
         assert_eq!(super::split_index(19), (2, 3));
     }
+
+    #[test]
+    fn normalizing_trims_wasted_bits() {
+        let mut bit_array = super::BitArrayList::from(vec![255], 5);
+
+        bit_array.normalize();
+        assert_eq!(bit_array.bytes(), &vec![248]);
+    }
 }